@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use rand::Rng;
+
 pub struct NGramModel {
     unigram: HashMap<String, usize>,
     bigram: HashMap<(String, String), usize>,
@@ -75,28 +77,50 @@ impl NGramModel {
     }
 
     //──────────────────────────────────────────────────────────────────────────
-    // Laplace‑smoothed probability helpers
+    // Stupid backoff: a conditional scorer that actually depends on context
     //──────────────────────────────────────────────────────────────────────────
-    /// Laplace smoothing for n-gram models
-    ///
-    fn smooth_with_laplace(&self, current: &str) -> f64 {
-        // For bigram probability P(current|previous), we use:
-        // (count(previous, current) + 1) / (count(previous) + V)
-        // where V is the vocabulary size
-        let mut total_probability = 0.0;
+    /// The discount applied every time we back off from a richer context to a
+    /// sparser one (trigram → bigram → unigram). `0.4` is the standard
+    /// "stupid backoff" value — cheap to compute and good enough in practice.
+    const BACKOFF_LAMBDA: f64 = 0.4;
+
+    /// Score `P(word | ante_prev, prev)` via stupid backoff: use the trigram
+    /// relative frequency when `(ante_prev, prev, word)` was observed,
+    /// otherwise discount by [`Self::BACKOFF_LAMBDA`] and fall back to the
+    /// bigram estimate, and finally to the unigram relative frequency.
+    fn stupid_backoff_score(&self, ante_prev: Option<&str>, prev: Option<&str>, word: &str) -> f64 {
+        if let (Some(ante_prev), Some(prev)) = (ante_prev, prev) {
+            let trigram_count = self
+                .trigram
+                .get(&(ante_prev.to_string(), prev.to_string(), word.to_string()))
+                .copied()
+                .unwrap_or(0);
+            let context_count = self.bigram.get(&(ante_prev.to_string(), prev.to_string())).copied().unwrap_or(0);
+
+            if trigram_count > 0 && context_count > 0 {
+                return trigram_count as f64 / context_count as f64;
+            }
 
-        for (prev_word, _) in &self.unigram {
-            let bigram_count = self.bigram.get(&(prev_word.clone(), current.to_string())).unwrap_or(&0);
-            let prev_word_count = self.unigram.get(prev_word).unwrap_or(&0);
+            return Self::BACKOFF_LAMBDA * self.stupid_backoff_score(None, Some(prev), word);
+        }
 
-            // Apply Laplace smoothing
-            let smoothed_probability = ((*bigram_count as f64) + 1.0) /
-                                    ((*prev_word_count as f64) + (self.vocab_count as f64));
+        if let Some(prev) = prev {
+            let bigram_count = self.bigram.get(&(prev.to_string(), word.to_string())).copied().unwrap_or(0);
+            let prev_count = self.unigram.get(prev).copied().unwrap_or(0);
 
-            total_probability += smoothed_probability;
+            if bigram_count > 0 && prev_count > 0 {
+                return bigram_count as f64 / prev_count as f64;
+            }
+
+            return Self::BACKOFF_LAMBDA * self.stupid_backoff_score(None, None, word);
+        }
+
+        let total_tokens: usize = self.unigram.values().sum();
+        if total_tokens == 0 {
+            return 0.0;
         }
 
-        total_probability
+        self.unigram.get(word).copied().unwrap_or(0) as f64 / total_tokens as f64
     }
 }
 
@@ -127,7 +151,9 @@ impl NGramModel {
         return best_candidate;
     }
 
-    /// Suggest next word using bigram counts.
+    /// Suggest next word using bigram counts, ranked by the stupid-backoff
+    /// score of `P(word | current_word)` so the suggestion actually depends
+    /// on the preceding word instead of being the same regardless of context.
     pub fn suggest_bigram(&self, input: &str) -> (String, usize) {
         // 1. Tokenize input and ensure we have enough tokens
         let tokenized_input = Self::tokenize(input);
@@ -139,26 +165,28 @@ impl NGramModel {
         // Extract current word to use as context
         let current_word = tokenized_input.last().unwrap();
 
-        let mut candidates: Vec<(String, f64)> = Vec::new();
-
-        // For each word in vocabulary, calculate its probability given the current word
-        for word in self.unigram.keys() {
-            let probability = self.smooth_with_laplace(word);
-            candidates.push((word.clone(), probability));
-        }
+        // Only candidates actually observed following `current_word` are
+        // worth ranking; anything else would score via the unigram floor.
+        let mut candidates: Vec<(String, f64)> = self
+            .bigram
+            .keys()
+            .filter(|(prev_word, _)| prev_word == current_word)
+            .map(|(_, word)| (word.clone(), self.stupid_backoff_score(None, Some(current_word), word)))
+            .collect();
 
-        // Sort by probability descending
+        // Sort by score descending
         candidates.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
 
-        // Convert probability back to a count-like number by scaling
+        // Convert score back to a count-like number by scaling
         let best_candidate = candidates.first()
-            .map(|(word, prob)| (word.clone(), (prob * 1000.0) as usize))
+            .map(|(word, score)| (word.clone(), (score * 1000.0) as usize))
             .unwrap_or((String::new(), 0));
 
         return best_candidate;
     }
 
-    /// Suggest next word using trigram counts
+    /// Suggest next word using trigram counts, ranked by the stupid-backoff
+    /// score of `P(word | previous, current)`.
     pub fn suggest_trigram(&self, input: &str) -> (String, usize) {
         // 1. Tokenize input and ensure we have enough tokens
         let tokenized_input = Self::tokenize(input);
@@ -171,21 +199,467 @@ impl NGramModel {
         let current: String = tokenized_input[tokenized_input.len() - 1].clone();
         let previous: String = tokenized_input[tokenized_input.len() - 2].clone();
 
-        // 3‑5. Filter on exact previous matches and current prefix, map to (word, count)
-        let mut candidates: Vec<(String, usize)> = self
+        // 3‑5. Filter on exact previous matches, score by stupid backoff
+        let mut candidates: Vec<(String, f64)> = self
             .trigram
-            .iter()
-            .filter(|((ante_prev_word, prev_word, _), _)| {
+            .keys()
+            .filter(|(ante_prev_word, prev_word, _)| {
                 ante_prev_word == &previous && prev_word == &current
             })
-            .map(|((_, _, current), count)| (current.clone(), *count))
+            .map(|(_, _, word)| (word.clone(), self.stupid_backoff_score(Some(&previous), Some(&current), word)))
             .collect();
 
-        // 6. Sort by count descending and take the best match
-        candidates.sort_by(|a, b| b.1.cmp(&a.1));
+        // 6. Sort by score descending and take the best match
+        candidates.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
 
-        let best_candidate = candidates.first().cloned().unwrap_or((String::new(), 0));
+        let best_candidate = candidates.first()
+            .map(|(word, score)| (word.clone(), (score * 1000.0) as usize))
+            .unwrap_or((String::new(), 0));
 
         return best_candidate;
     }
 }
+
+impl NGramModel {
+    //──────────────────────────────────────────────────────────────────────────
+    // Segmentation: splitting run-together text via Viterbi search
+    //──────────────────────────────────────────────────────────────────────────
+    /// The longest word we're willing to consider when splitting `text`. Without
+    /// a cap the DP below would try every prefix of a long input as a single
+    /// "word", which is both slow and never the right answer in practice.
+    const MAX_SEGMENT_WORD_LEN: usize = 24;
+
+    /// Split a run-together string (e.g. a hashtag or domain name) into a
+    /// high-probability sequence of words. Substrings never seen in training
+    /// can still appear in the result, scored with a small floor probability.
+    ///
+    /// This runs a Viterbi-style dynamic program over character positions:
+    /// `best[i]` holds the best total log-probability found for a
+    /// segmentation of the first `i` characters, and `back[i]` remembers where
+    /// the last word in that segmentation started so the split can be
+    /// reconstructed at the end. Each word is scored against only the last
+    /// word of the best split kept for the prefix before it, so a split whose
+    /// prefix scored lower but whose bigram would score higher is not
+    /// considered.
+    pub fn segment(&self, text: &str) -> Vec<String> {
+        let chars: Vec<char> = text.to_lowercase().chars().collect();
+        let n = chars.len();
+
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let mut best = vec![f64::NEG_INFINITY; n + 1];
+        let mut back = vec![0usize; n + 1];
+        let mut word_at = vec![String::new(); n + 1];
+        best[0] = 0.0;
+
+        for i in 1..=n {
+            let start = i.saturating_sub(Self::MAX_SEGMENT_WORD_LEN);
+            for j in start..i {
+                if best[j] == f64::NEG_INFINITY {
+                    continue;
+                }
+
+                let word: String = chars[j..i].iter().collect();
+                let prev_word = if j == 0 { None } else { Some(word_at[j].as_str()) };
+                let score = best[j] + self.segment_word_log_prob(&word, prev_word);
+
+                if score > best[i] {
+                    best[i] = score;
+                    back[i] = j;
+                    word_at[i] = word;
+                }
+            }
+        }
+
+        // Walk the backpointers from the end to reconstruct the winning split.
+        let mut words = Vec::new();
+        let mut i = n;
+        while i > 0 {
+            let j = back[i];
+            words.push(chars[j..i].iter().collect::<String>());
+            i = j;
+        }
+        words.reverse();
+        words
+    }
+
+    /// Smoothed log-probability of `word` following `prev_word` (if any),
+    /// blending the bigram estimate with the unigram one and falling back to
+    /// a floor probability for substrings that were never seen during
+    /// training, so nonsense splits are still scored (just penalized).
+    fn segment_word_log_prob(&self, word: &str, prev_word: Option<&str>) -> f64 {
+        let total_tokens: usize = self.unigram.values().sum();
+        let floor = 1.0 / (self.vocab_count.max(1) as f64 * 10f64.powi(word.chars().count() as i32));
+
+        let unigram_prob = match self.unigram.get(word) {
+            Some(&count) if total_tokens > 0 => count as f64 / total_tokens as f64,
+            _ => floor,
+        };
+
+        let blended = match prev_word {
+            Some(prev) => {
+                let prev_count = self.unigram.get(prev).copied().unwrap_or(0);
+                let bigram_count = self
+                    .bigram
+                    .get(&(prev.to_string(), word.to_string()))
+                    .copied()
+                    .unwrap_or(0);
+
+                if bigram_count > 0 && prev_count > 0 {
+                    0.6 * (bigram_count as f64 / prev_count as f64) + 0.4 * unigram_prob
+                } else {
+                    unigram_prob
+                }
+            }
+            None => unigram_prob,
+        };
+
+        blended.max(floor).ln()
+    }
+}
+
+impl NGramModel {
+    //──────────────────────────────────────────────────────────────────────────
+    // Generation: sampling fresh text from the n-gram distributions
+    //──────────────────────────────────────────────────────────────────────────
+    /// A handful of tokens that are treated as a natural place to stop, since
+    /// the tokenizer strips sentence punctuation before counts are built.
+    const END_TOKENS: &'static [&'static str] = &["end", "eos", "stop"];
+
+    /// Generate fresh text by *sampling* from the trained distributions
+    /// instead of always taking the argmax completion, using a default
+    /// temperature of `1.0` (an unscaled draw from the trained counts). See
+    /// [`Self::generate_with_temperature`] for control over how greedy vs.
+    /// diverse the sampling is.
+    pub fn generate(&self, seed: &str, max_tokens: usize) -> String {
+        self.generate_with_temperature(seed, max_tokens, 1.0)
+    }
+
+    /// Like [`Self::generate`], but `temperature` raises each candidate count
+    /// to `1/temperature` before normalizing: values below `1.0` sharpen the
+    /// distribution towards the most frequent continuations, values above
+    /// `1.0` flatten it towards more diverse (and more surprising) ones.
+    pub fn generate_with_temperature(&self, seed: &str, max_tokens: usize, temperature: f64) -> String {
+        let mut tokens = Self::tokenize(seed);
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..max_tokens {
+            let next = match self.sample_next(&tokens, temperature, &mut rng) {
+                Some(word) => word,
+                None => break,
+            };
+
+            let reached_end = Self::END_TOKENS.contains(&next.as_str());
+            tokens.push(next);
+
+            if reached_end {
+                break;
+            }
+        }
+
+        tokens.join(" ")
+    }
+
+    /// Sample one next word given the trailing context, backing off from
+    /// trigram to bigram to unigram as the context goes unseen.
+    fn sample_next(&self, tokens: &[String], temperature: f64, rng: &mut impl Rng) -> Option<String> {
+        if tokens.len() >= 2 {
+            let ante_prev = &tokens[tokens.len() - 2];
+            let prev = &tokens[tokens.len() - 1];
+
+            let candidates: Vec<(&String, usize)> = self
+                .trigram
+                .iter()
+                .filter(|((a, b, _), _)| a == ante_prev && b == prev)
+                .map(|((_, _, word), count)| (word, *count))
+                .collect();
+
+            if let Some(word) = Self::sample_from_counts(&candidates, temperature, rng) {
+                return Some(word);
+            }
+        }
+
+        if let Some(prev) = tokens.last() {
+            let candidates: Vec<(&String, usize)> = self
+                .bigram
+                .iter()
+                .filter(|((a, _), _)| a == prev)
+                .map(|((_, word), count)| (word, *count))
+                .collect();
+
+            if let Some(word) = Self::sample_from_counts(&candidates, temperature, rng) {
+                return Some(word);
+            }
+        }
+
+        let candidates: Vec<(&String, usize)> = self.unigram.iter().map(|(word, count)| (word, *count)).collect();
+        Self::sample_from_counts(&candidates, temperature, rng)
+    }
+
+    /// Draw one candidate via inverse-CDF sampling over `candidates`, after
+    /// raising each count to `1/temperature` and normalizing.
+    fn sample_from_counts(candidates: &[(&String, usize)], temperature: f64, rng: &mut impl Rng) -> Option<String> {
+        if candidates.is_empty() {
+            return None;
+        }
+
+        let exponent = 1.0 / temperature.max(1e-6);
+        let weights: Vec<f64> = candidates.iter().map(|(_, count)| (*count as f64).powf(exponent)).collect();
+        let total: f64 = weights.iter().sum();
+
+        if total <= 0.0 {
+            return None;
+        }
+
+        let threshold = rng.gen::<f64>() * total;
+        let mut cumulative = 0.0;
+
+        for ((word, _), weight) in candidates.iter().zip(weights.iter()) {
+            cumulative += weight;
+            if cumulative >= threshold {
+                return Some((*word).clone());
+            }
+        }
+
+        candidates.last().map(|(word, _)| (*word).clone())
+    }
+}
+
+impl NGramModel {
+    //──────────────────────────────────────────────────────────────────────────
+    // Evaluation: scoring held-out text against the trained counts
+    //──────────────────────────────────────────────────────────────────────────
+    /// Total log-probability the model assigns to `text`, summing the
+    /// Laplace-smoothed probability of each token: the unigram probability
+    /// for the first token, the bigram conditional for the second, and the
+    /// trigram conditional for every later token (an unseen context is not
+    /// backed off; Laplace smoothing gives it `1/V`).
+    pub fn log_probability(&self, text: &str) -> f64 {
+        let tokens = Self::tokenize(text);
+        let mut total_log_prob = 0.0;
+
+        for (i, word) in tokens.iter().enumerate() {
+            let probability = if i >= 2 {
+                self.laplace_trigram_probability(&tokens[i - 2], &tokens[i - 1], word)
+            } else if i == 1 {
+                self.laplace_bigram_probability(&tokens[i - 1], word)
+            } else {
+                self.laplace_unigram_probability(word)
+            };
+
+            total_log_prob += probability.ln();
+        }
+
+        total_log_prob
+    }
+
+    /// Perplexity of `text` under the model: `exp(-1/N * log_probability(text))`
+    /// over the `N` tokens in `text`. Lower is better; it's the standard
+    /// metric for comparing two language models on the same held-out corpus.
+    pub fn perplexity(&self, text: &str) -> f64 {
+        let tokens = Self::tokenize(text);
+
+        if tokens.is_empty() {
+            return f64::INFINITY;
+        }
+
+        let avg_neg_log_prob = -self.log_probability(text) / tokens.len() as f64;
+        avg_neg_log_prob.exp()
+    }
+
+    /// Laplace-smoothed `P(word)`: `(count(word)+1) / (total_tokens+V)`.
+    fn laplace_unigram_probability(&self, word: &str) -> f64 {
+        let count = self.unigram.get(word).copied().unwrap_or(0);
+        let total_tokens: usize = self.unigram.values().sum();
+
+        (count as f64 + 1.0) / (total_tokens as f64 + self.vocab_count as f64)
+    }
+
+    /// Laplace-smoothed `P(word | prev)`: `(count(prev,word)+1) / (count(prev)+V)`.
+    fn laplace_bigram_probability(&self, prev: &str, word: &str) -> f64 {
+        let bigram_count = self.bigram.get(&(prev.to_string(), word.to_string())).copied().unwrap_or(0);
+        let context_count = self.unigram.get(prev).copied().unwrap_or(0);
+
+        (bigram_count as f64 + 1.0) / (context_count as f64 + self.vocab_count as f64)
+    }
+
+    /// Laplace-smoothed `P(word | ante_prev, prev)`:
+    /// `(count(ante_prev,prev,word)+1) / (count(ante_prev,prev)+V)`.
+    fn laplace_trigram_probability(&self, ante_prev: &str, prev: &str, word: &str) -> f64 {
+        let trigram_count = self
+            .trigram
+            .get(&(ante_prev.to_string(), prev.to_string(), word.to_string()))
+            .copied()
+            .unwrap_or(0);
+        let context_count = self.bigram.get(&(ante_prev.to_string(), prev.to_string())).copied().unwrap_or(0);
+
+        (trigram_count as f64 + 1.0) / (context_count as f64 + self.vocab_count as f64)
+    }
+}
+
+/// Association measure used to rank candidate collocations in
+/// [`NGramModel::collocations`].
+pub enum Assoc {
+    /// Pointwise mutual information: how much more often `(a, b)` co-occurs
+    /// than chance would predict given how common `a` and `b` are alone.
+    Pmi,
+    /// Pearson's chi-square statistic over the 2×2 contingency table of
+    /// `(a,b)`, `(a,¬b)`, `(¬a,b)`, `(¬a,¬b)` counts.
+    ChiSquare,
+}
+
+impl NGramModel {
+    //──────────────────────────────────────────────────────────────────────────
+    // Collocations: surfacing statistically significant two-word phrases
+    //──────────────────────────────────────────────────────────────────────────
+    /// Bigrams seen fewer than this many times are dropped before scoring,
+    /// since rare pairs can have spuriously high PMI.
+    const MIN_COLLOCATION_COUNT: usize = 3;
+
+    /// Rank the observed bigrams by `measure` and return the top `top_k`
+    /// pairs, filtering out anything below [`Self::MIN_COLLOCATION_COUNT`]
+    /// occurrences first.
+    pub fn collocations(&self, measure: Assoc, top_k: usize) -> Vec<((String, String), f64)> {
+        let total_tokens: usize = self.unigram.values().sum();
+        let n = total_tokens as f64;
+
+        let mut scored: Vec<((String, String), f64)> = self
+            .bigram
+            .iter()
+            .filter(|(_, &count)| count >= Self::MIN_COLLOCATION_COUNT)
+            .map(|((a, b), &count)| {
+                let score = match measure {
+                    Assoc::Pmi => self.pmi(a, b, count, n),
+                    Assoc::ChiSquare => self.chi_square(a, b, count, n),
+                };
+                ((a.clone(), b.clone()), score)
+            })
+            .collect();
+
+        scored.sort_by(|x, y| y.1.partial_cmp(&x.1).unwrap_or(std::cmp::Ordering::Equal));
+        scored.truncate(top_k);
+        scored
+    }
+
+    /// `PMI(a, b) = log( count(a,b) * N / (count(a) * count(b)) )`.
+    fn pmi(&self, a: &str, b: &str, count_ab: usize, n: f64) -> f64 {
+        let count_a = self.unigram.get(a).copied().unwrap_or(0) as f64;
+        let count_b = self.unigram.get(b).copied().unwrap_or(0) as f64;
+
+        if count_a == 0.0 || count_b == 0.0 {
+            return f64::NEG_INFINITY;
+        }
+
+        ((count_ab as f64 * n) / (count_a * count_b)).ln()
+    }
+
+    /// Pearson's chi-square statistic over the 2×2 contingency table built
+    /// from the unigram/bigram counts of `a` and `b`.
+    fn chi_square(&self, a: &str, b: &str, count_ab: usize, n: f64) -> f64 {
+        let count_a = self.unigram.get(a).copied().unwrap_or(0) as f64;
+        let count_b = self.unigram.get(b).copied().unwrap_or(0) as f64;
+
+        let o11 = count_ab as f64; // a followed by b
+        let o12 = count_a - o11; // a followed by something else
+        let o21 = count_b - o11; // something else followed by b
+        let o22 = n - count_a - count_b + o11; // neither
+
+        let numerator = n * (o11 * o22 - o12 * o21).powi(2);
+        let denominator = (o11 + o12) * (o11 + o21) * (o12 + o22) * (o21 + o22);
+
+        if denominator <= 0.0 {
+            return 0.0;
+        }
+
+        numerator / denominator
+    }
+}
+
+impl NGramModel {
+    //──────────────────────────────────────────────────────────────────────────
+    // RAKE: keyword extraction from candidate phrases
+    //──────────────────────────────────────────────────────────────────────────
+    /// Stopwords used to split `text` into candidate keyword phrases. Not
+    /// exhaustive, just enough to break sentences at the usual joints.
+    const STOPWORDS: &'static [&'static str] = &[
+        "a", "an", "the", "and", "or", "but", "if", "then", "so", "because", "of", "in", "on", "at",
+        "to", "for", "with", "as", "by", "from", "is", "are", "was", "were", "be", "been", "being",
+        "this", "that", "these", "those", "it", "its", "which", "who", "whom", "what", "when",
+        "where", "how", "not", "no", "do", "does", "did", "can", "could", "will", "would", "should",
+        "i", "you", "he", "she", "we", "they", "my", "your", "his", "her", "our", "their",
+    ];
+
+    /// Extract the top `top_k` RAKE keyword phrases from `text`.
+    ///
+    /// Candidate phrases are contiguous runs of content words, found by
+    /// breaking `text` on stopwords and punctuation. Each content word gets a
+    /// frequency `freq(w)` and a degree `deg(w)` (the total length of every
+    /// candidate phrase it appears in, counting itself), scored as
+    /// `deg(w)/freq(w)`. A phrase's score is the sum of its words' scores.
+    pub fn extract_keywords(&self, text: &str, top_k: usize) -> Vec<(String, f64)> {
+        let phrases = Self::rake_phrases(text);
+
+        let mut freq: HashMap<String, usize> = HashMap::new();
+        let mut degree: HashMap<String, usize> = HashMap::new();
+
+        for phrase in &phrases {
+            let phrase_len = phrase.len();
+            for word in phrase {
+                *freq.entry(word.clone()).or_insert(0) += 1;
+                *degree.entry(word.clone()).or_insert(0) += phrase_len;
+            }
+        }
+
+        let word_score = |word: &str| -> f64 {
+            let f = freq.get(word).copied().unwrap_or(0) as f64;
+            let d = degree.get(word).copied().unwrap_or(0) as f64;
+            if f == 0.0 {
+                0.0
+            } else {
+                d / f
+            }
+        };
+
+        let mut phrase_scores: HashMap<String, f64> = HashMap::new();
+        for phrase in &phrases {
+            let score: f64 = phrase.iter().map(|word| word_score(word)).sum();
+            phrase_scores.entry(phrase.join(" ")).or_insert(score);
+        }
+
+        let mut ranked: Vec<(String, f64)> = phrase_scores.into_iter().collect();
+        ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        ranked.truncate(top_k);
+        ranked
+    }
+
+    /// Split `text` into candidate phrases: contiguous runs of content words,
+    /// breaking on stopwords and punctuation, reusing the same lowercasing
+    /// used by [`Self::tokenize`].
+    fn rake_phrases(text: &str) -> Vec<Vec<String>> {
+        let words: Vec<String> = text
+            .split(|c: char| c.is_whitespace() || !c.is_alphanumeric())
+            .map(|w| w.to_lowercase())
+            .collect();
+
+        let mut phrases = Vec::new();
+        let mut current = Vec::new();
+
+        for word in words {
+            if word.is_empty() || Self::STOPWORDS.contains(&word.as_str()) {
+                if !current.is_empty() {
+                    phrases.push(std::mem::take(&mut current));
+                }
+            } else {
+                current.push(word);
+            }
+        }
+
+        if !current.is_empty() {
+            phrases.push(current);
+        }
+
+        phrases
+    }
+}